@@ -1,8 +1,10 @@
 //! A simple Fiat-Shamir transcript that uses a Keccak256 hash chain.
 
+use ark_ec::AffineRepr;
+use ark_ff::PrimeField;
 use byteorder::{ByteOrder, LittleEndian};
 use std::convert::TryInto;
-use tiny_keccak::{Hasher, Keccak};
+use tiny_keccak::{keccakf, Hasher, Keccak};
 
 /// Encode a u64 as a little-endian "u256", i.e. a 32-byte array
 fn encode_u64_as_u256_le(x: u64) -> [u8; 32] {
@@ -41,6 +43,82 @@ pub fn pad_label(label: &[u8]) -> [u8; 32] {
         .unwrap()
 }
 
+/// A Fiat-Shamir transcript that protocol code (Schnorr, sigma-protocols,
+/// ...) can be written against generically, so it isn't tied to a concrete
+/// hash backend such as [`HashChainTranscript`]. Implementors of this trait
+/// get `commit_point`/`commit_scalar` for free, built on the required
+/// methods.
+///
+/// `challenge_scalar` is not a trait method: generic code that needs a
+/// field-element challenge calls the free [`challenge_scalar`] function,
+/// which works for any `T: Transcript`. `build_rng`'s return type is an
+/// associated type rather than a fixed concrete builder, so each backend
+/// can fork into its own RNG type instead of being forced through
+/// [`HashChainTranscript`]'s. Because `commit_point` and `commit_scalar`
+/// are generic methods, `Transcript` is not object safe: use it through
+/// static dispatch (`impl Transcript` / `T: Transcript`), not as
+/// `dyn Transcript`.
+pub trait Transcript {
+    /// The RNG-forking builder type produced by [`Transcript::build_rng`]
+    type RngBuilder;
+
+    /// Absorb a message into the transcript state
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]);
+
+    /// Absorb a u64 into the transcript state
+    fn append_u64(&mut self, label: &'static [u8], x: u64);
+
+    /// Squeeze 32 challenge bytes out of the transcript state
+    fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]);
+
+    /// Fork the transcript to construct an RNG whose output is bound to the
+    /// current transcript state as well as prover's secrets
+    fn build_rng(&self) -> Self::RngBuilder;
+
+    /// Absorb the canonical serialization of a curve point
+    fn commit_point<P: AffineRepr>(&mut self, label: &'static [u8], point: &P) {
+        let mut bytes = Vec::new();
+        point
+            .serialize_compressed(&mut bytes)
+            .expect("serialization to a `Vec` should not fail");
+        self.append_message(label, &bytes);
+    }
+
+    /// Absorb the canonical serialization of a field element
+    fn commit_scalar<F: PrimeField>(&mut self, label: &'static [u8], scalar: &F) {
+        let mut bytes = Vec::new();
+        scalar
+            .serialize_compressed(&mut bytes)
+            .expect("serialization to a `Vec` should not fail");
+        self.append_message(label, &bytes);
+    }
+}
+
+/// Squeeze a uniformly random scalar in the field `F` from any transcript
+/// backend implementing [`Transcript`].
+///
+/// Squeezes 64 bytes of challenge material (double the 32 bytes returned by
+/// [`Transcript::challenge_bytes`]) so that reducing modulo `F`'s modulus
+/// biases the result away from uniform by no more than 2^-128, regardless
+/// of how close the modulus is to a power of two. The two 32-byte squeezes
+/// are domain-separated by an internal counter label so the transcript
+/// state still advances deterministically.
+pub fn challenge_scalar<T: Transcript, F: PrimeField>(transcript: &mut T, label: &'static [u8]) -> F {
+    let mut lo = [0u8; 32];
+    let mut hi = [0u8; 32];
+
+    transcript.append_u64(b"challenge_scalar_ctr", 0);
+    transcript.challenge_bytes(label, &mut lo);
+    transcript.append_u64(b"challenge_scalar_ctr", 1);
+    transcript.challenge_bytes(label, &mut hi);
+
+    let mut wide = [0u8; 64];
+    wide[..32].copy_from_slice(&lo);
+    wide[32..].copy_from_slice(&hi);
+
+    F::from_le_bytes_mod_order(&wide)
+}
+
 #[derive(Clone)]
 pub struct HashChainTranscript {
     state: [u8; 32],
@@ -87,6 +165,49 @@ impl HashChainTranscript {
         dest.copy_from_slice(&output);
     }
 
+    /// Squeeze a uniformly random scalar in the field `F`. See the free
+    /// [`challenge_scalar`] function for details.
+    pub fn challenge_scalar<F: PrimeField>(&mut self, label: &'static [u8]) -> F {
+        challenge_scalar(self, label)
+    }
+
+    /// Squeeze an arbitrary-length stream of challenge bytes into `dest`.
+    ///
+    /// Unlike [`HashChainTranscript::challenge_bytes`], which always
+    /// produces exactly 32 bytes, this mirrors the extendable-output
+    /// ("XOF") transcript pattern used by schnorrkel: block `i` is
+    /// `keccak256(pad_label(label) || state || encode_u64_as_u256_le(i))`,
+    /// and the stream is the concatenation of these blocks truncated to
+    /// `dest.len()`. The final block is folded back into `self.state` so
+    /// the transcript continues to evolve deterministically.
+    ///
+    /// At least one block is always squeezed and folded back into
+    /// `self.state`, even when `dest` is empty, so the transcript's
+    /// evolution never silently depends on the requested output length.
+    pub fn challenge_bytes_xof(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        let num_blocks = std::cmp::max(1, dest.len().div_ceil(32));
+        let mut block = [0u8; 32];
+
+        for i in 0..num_blocks {
+            let data: Vec<u8> = pad_label(label)
+                .iter()
+                .chain(self.state.iter())
+                .chain(encode_u64_as_u256_le(i as u64).iter())
+                .cloned()
+                .collect();
+
+            keccak256(&data, &mut block);
+
+            let start = i * 32;
+            let end = std::cmp::min(start + 32, dest.len());
+            if start < end {
+                dest[start..end].copy_from_slice(&block[..end - start]);
+            }
+        }
+
+        self.state.copy_from_slice(&block);
+    }
+
     /// Fork the current [`HashChainTranscript`] to construct an RNG whose output is bound
     /// to the current transcript state as well as prover's secrets.
     pub fn build_rng(&self) -> HashChainTranscriptRngBuilder {
@@ -94,6 +215,490 @@ impl HashChainTranscript {
             transcript: self.clone(),
         }
     }
+
+    /// Construct a transcript directly from a previously-extracted state,
+    /// e.g. one received from [`HashChainTranscript::state`] after crossing
+    /// a serialization boundary (checkpoint/resume, distributed proving).
+    pub fn from_state(state: [u8; 32]) -> Self {
+        HashChainTranscript { state }
+    }
+
+    /// The raw 32-byte transcript state.
+    pub fn state(&self) -> [u8; 32] {
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod xof_tests {
+    use super::*;
+
+    /// Squeezing into an empty `dest` must still advance the transcript
+    /// state, the same as every other squeeze path.
+    #[test]
+    fn empty_xof_squeeze_still_advances_state() {
+        let mut transcript = HashChainTranscript::new(b"xof test");
+        let state_before = transcript.state();
+
+        transcript.challenge_bytes_xof(b"challenge", &mut []);
+
+        assert_ne!(transcript.state(), state_before);
+    }
+
+    /// An XOF squeeze that needs two blocks must not simply repeat the
+    /// first block: each block is domain-separated by its index.
+    #[test]
+    fn xof_blocks_are_domain_separated() {
+        let mut transcript = HashChainTranscript::new(b"xof test");
+
+        let mut out = [0u8; 64];
+        transcript.challenge_bytes_xof(b"challenge", &mut out);
+
+        assert_ne!(out[..32], out[32..]);
+    }
+
+    /// `from_state` must reconstruct a transcript whose `state()` matches
+    /// the one it was built from, independent of the `serde` feature.
+    #[test]
+    fn from_state_round_trips_with_state() {
+        let transcript = HashChainTranscript::new(b"test transcript");
+        let restored = HashChainTranscript::from_state(transcript.state());
+
+        assert_eq!(transcript.state(), restored.state());
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for HashChainTranscript {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.state.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HashChainTranscript {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let state = <[u8; 32]>::deserialize(deserializer)?;
+        Ok(HashChainTranscript { state })
+    }
+}
+
+impl Transcript for HashChainTranscript {
+    type RngBuilder = HashChainTranscriptRngBuilder;
+
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        HashChainTranscript::append_message(self, label, message)
+    }
+
+    fn append_u64(&mut self, label: &'static [u8], x: u64) {
+        HashChainTranscript::append_u64(self, label, x)
+    }
+
+    fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        HashChainTranscript::challenge_bytes(self, label, dest)
+    }
+
+    fn build_rng(&self) -> HashChainTranscriptRngBuilder {
+        HashChainTranscript::build_rng(self)
+    }
+}
+
+/// A length-prefixed framing mode for [`HashChainTranscript`].
+///
+/// The default hash chain absorbs `message || pad_label(label) || state`
+/// with no length information, so two different `(label, message)` pairs
+/// whose concatenations happen to coincide hash to the same state. This
+/// wrapper closes that ambiguity by length-prefixing every absorbed field,
+/// at the cost of no longer matching the Cairo-compatible byte-for-byte
+/// encoding. It is opt-in and version-tagged so its state can never be
+/// confused with (or replayed against) a [`HashChainTranscript`].
+#[derive(Clone)]
+pub struct FramedHashChainTranscript {
+    inner: HashChainTranscript,
+}
+
+impl FramedHashChainTranscript {
+    /// Domain separator mixed into the seed so a framed transcript's state
+    /// can never collide with the default hash-chain encoding.
+    const VERSION_LABEL: &'static [u8] = b"merlin-framed-v1";
+
+    /// Create a new framed transcript, seeded with the given `label`.
+    pub fn new(label: &'static [u8]) -> Self {
+        let mut state = [0u8; 32];
+        let seed: Vec<u8> = Self::VERSION_LABEL
+            .iter()
+            .chain(pad_label(label).iter())
+            .cloned()
+            .collect();
+
+        keccak256(&seed, &mut state);
+        FramedHashChainTranscript {
+            inner: HashChainTranscript { state },
+        }
+    }
+
+    /// Absorb a length-prefixed message into the transcript state.
+    pub fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        let data: Vec<u8> = encode_u64_as_u256_le(message.len() as u64)
+            .iter()
+            .chain(message.iter())
+            .chain(encode_u64_as_u256_le(label.len() as u64).iter())
+            .chain(pad_label(label).iter())
+            .chain(self.inner.state.iter())
+            .cloned()
+            .collect();
+
+        keccak256(&data, self.inner.state.as_mut());
+    }
+
+    /// Absorb a u64 into the transcript state
+    pub fn append_u64(&mut self, label: &'static [u8], x: u64) {
+        self.append_message(label, &encode_u64_as_u256_le(x));
+    }
+
+    /// Squeeze 32 challenge bytes out of the transcript state
+    pub fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        let data: Vec<u8> = encode_u64_as_u256_le(label.len() as u64)
+            .iter()
+            .chain(pad_label(label).iter())
+            .chain(self.inner.state.iter())
+            .cloned()
+            .collect();
+
+        let mut output = [0u8; 32];
+        keccak256(&data, &mut output);
+
+        self.inner.state.copy_from_slice(&output);
+        dest.copy_from_slice(&output);
+    }
+}
+
+/// A generic counterpart to [`HashChainTranscriptRngBuilder`], forking an
+/// RNG from any backend implementing [`Transcript`] rather than from
+/// [`HashChainTranscript`] specifically.
+pub struct TranscriptRngBuilder<T: Transcript> {
+    transcript: T,
+}
+
+impl<T: Transcript> TranscriptRngBuilder<T> {
+    /// Rekey the transcript using the provided witness data.
+    ///
+    /// The `label` parameter is metadata about `witness`.
+    pub fn rekey_with_witness_bytes(mut self, label: &'static [u8], witness: &[u8]) -> Self {
+        self.transcript.append_message(label, witness);
+        self
+    }
+
+    /// Use the supplied external `rng` to rekey the transcript, so
+    /// that the finalized [`TranscriptRng`] is a PRF bound to
+    /// randomness from the external RNG, as well as all other
+    /// transcript data.
+    pub fn finalize<R>(mut self, rng: &mut R) -> TranscriptRng<T>
+    where
+        R: rand_core::RngCore + rand_core::CryptoRng,
+    {
+        let random_bytes = {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            bytes
+        };
+
+        self.transcript.append_message(b"rng", &random_bytes);
+
+        TranscriptRng {
+            transcript: self.transcript,
+        }
+    }
+}
+
+/// A generic counterpart to [`HashChainTranscriptRng`], implementing
+/// [`rand_core::RngCore`] for any backend implementing [`Transcript`].
+pub struct TranscriptRng<T: Transcript> {
+    transcript: T,
+}
+
+impl<T: Transcript> rand_core::RngCore for TranscriptRng<T> {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0_u8; 32];
+        self.transcript.challenge_bytes(b"next_u32", &mut bytes);
+        u32::from_le_bytes(bytes[0..4].try_into().unwrap())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0_u8; 32];
+        self.transcript.challenge_bytes(b"next_u64", &mut bytes);
+        u64::from_le_bytes(bytes[0..8].try_into().unwrap())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl<T: Transcript> rand_core::CryptoRng for TranscriptRng<T> {}
+
+impl Transcript for FramedHashChainTranscript {
+    type RngBuilder = TranscriptRngBuilder<FramedHashChainTranscript>;
+
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        FramedHashChainTranscript::append_message(self, label, message)
+    }
+
+    fn append_u64(&mut self, label: &'static [u8], x: u64) {
+        FramedHashChainTranscript::append_u64(self, label, x)
+    }
+
+    fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        FramedHashChainTranscript::challenge_bytes(self, label, dest)
+    }
+
+    fn build_rng(&self) -> Self::RngBuilder {
+        TranscriptRngBuilder {
+            transcript: self.clone(),
+        }
+    }
+}
+
+/// A streaming duplex-sponge transcript backend.
+///
+/// [`HashChainTranscript`] allocates a fresh `Vec` and spins up a new
+/// `Keccak::v256()` on every `append_message`/`challenge_bytes` call,
+/// rehashing the growing preimage each time. This backend instead keeps a
+/// single resident 1600-bit Keccak-`f` state and absorbs/squeezes directly
+/// into it, so appending `N` fields costs `O(total bytes)` permutations
+/// rather than `O(N)` full rehashes. It does not produce the same byte
+/// stream as [`HashChainTranscript`]; use the hash-chain backend where
+/// Cairo byte-exact compatibility is required.
+pub struct DuplexHashChainTranscript {
+    /// The full 1600-bit Keccak-`f` state, kept resident between operations
+    state: [u64; 25],
+    /// Byte offset into the rate portion of `state` for the next absorb/squeeze
+    offset: usize,
+    /// `true` while absorbing, `false` once the sponge has started squeezing
+    absorbing: bool,
+}
+
+impl DuplexHashChainTranscript {
+    /// The sponge rate in bytes, matching Keccak-256's parameters
+    /// (1088-bit rate, 512-bit capacity).
+    const RATE: usize = 136;
+
+    /// Create a new duplex transcript, seeded with the given `label`.
+    pub fn new(label: &'static [u8]) -> Self {
+        let mut transcript = DuplexHashChainTranscript {
+            state: [0u64; 25],
+            offset: 0,
+            absorbing: true,
+        };
+        transcript.absorb(&pad_label(label));
+        transcript
+    }
+
+    /// Permute the resident state and reset the rate offset.
+    fn permute(&mut self) {
+        keccakf(&mut self.state);
+        self.offset = 0;
+    }
+
+    /// Apply Keccak's `pad10*1` multi-rate padding to the current
+    /// (possibly partial) block and permute, marking the end of an
+    /// absorb or squeeze phase. Without this, a phase change would just
+    /// permute the raw, unpadded state, leaving no domain separation
+    /// between "more input still to come" and "phase has ended" -
+    /// exactly the kind of boundary ambiguity `FramedHashChainTranscript`
+    /// (see chunk0-2) closes for the hash-chain backend.
+    ///
+    /// If the previous squeeze ended exactly on a rate boundary
+    /// (`offset == RATE`), that full block has not been flushed yet -
+    /// flush it with a plain `permute()` first so the pad bits below land
+    /// in the fresh rate block rather than in the capacity region.
+    fn pad_and_permute(&mut self) {
+        if self.offset == Self::RATE {
+            self.permute();
+        }
+
+        let mut lane = self.state[self.offset / 8].to_le_bytes();
+        lane[self.offset % 8] ^= 0x06;
+        self.state[self.offset / 8] = u64::from_le_bytes(lane);
+
+        let last = Self::RATE - 1;
+        let mut last_lane = self.state[last / 8].to_le_bytes();
+        last_lane[last % 8] ^= 0x80;
+        self.state[last / 8] = u64::from_le_bytes(last_lane);
+
+        self.permute();
+    }
+
+    /// XOR `data` into the rate portion of the state in place, permuting on
+    /// every rate-sized block boundary. For large messages this absorbs the
+    /// slice directly rather than copying it into an intermediate buffer.
+    fn absorb(&mut self, mut data: &[u8]) {
+        if !self.absorbing {
+            self.pad_and_permute();
+            self.absorbing = true;
+        }
+
+        while !data.is_empty() {
+            let take = std::cmp::min(Self::RATE - self.offset, data.len());
+            for (i, &byte) in data[..take].iter().enumerate() {
+                let lane_offset = self.offset + i;
+                let mut lane = self.state[lane_offset / 8].to_le_bytes();
+                lane[lane_offset % 8] ^= byte;
+                self.state[lane_offset / 8] = u64::from_le_bytes(lane);
+            }
+
+            self.offset += take;
+            data = &data[take..];
+            if self.offset == Self::RATE {
+                self.permute();
+            }
+        }
+    }
+
+    /// Read `dest.len()` bytes out of the rate portion of the state,
+    /// permuting on every rate-sized block boundary.
+    fn squeeze(&mut self, dest: &mut [u8]) {
+        if self.absorbing {
+            self.pad_and_permute();
+            self.absorbing = false;
+        }
+
+        let mut written = 0;
+        while written < dest.len() {
+            if self.offset == Self::RATE {
+                self.permute();
+            }
+
+            let take = std::cmp::min(Self::RATE - self.offset, dest.len() - written);
+            for i in 0..take {
+                let lane_offset = self.offset + i;
+                let lane = self.state[lane_offset / 8].to_le_bytes();
+                dest[written + i] = lane[lane_offset % 8];
+            }
+
+            self.offset += take;
+            written += take;
+        }
+    }
+
+    /// Absorb a length-prefixed message into the transcript state.
+    ///
+    /// Like [`FramedHashChainTranscript`], each field is preceded by its
+    /// length so that two different `(label, message)` pairs whose
+    /// concatenations would otherwise coincide no longer hash to the same
+    /// state.
+    pub fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        self.absorb(&encode_u64_as_u256_le(message.len() as u64));
+        self.absorb(message);
+        self.absorb(&encode_u64_as_u256_le(label.len() as u64));
+        self.absorb(&pad_label(label));
+    }
+
+    /// Absorb a u64 into the transcript state
+    pub fn append_u64(&mut self, label: &'static [u8], x: u64) {
+        self.append_message(label, &encode_u64_as_u256_le(x));
+    }
+
+    /// Squeeze challenge bytes out of the transcript state
+    pub fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        self.absorb(&encode_u64_as_u256_le(label.len() as u64));
+        self.absorb(&pad_label(label));
+        self.squeeze(dest);
+    }
+}
+
+#[cfg(test)]
+mod duplex_tests {
+    use super::*;
+
+    /// Two duplex transcripts fed the same sequence of operations must
+    /// squeeze identical challenges, exercising the hand-rolled lane
+    /// XOR/permute bookkeeping above.
+    #[test]
+    fn duplex_is_deterministic() {
+        let mut a = DuplexHashChainTranscript::new(b"duplex test");
+        let mut b = DuplexHashChainTranscript::new(b"duplex test");
+
+        a.append_message(b"msg", b"hello, world");
+        b.append_message(b"msg", b"hello, world");
+        a.append_u64(b"count", 7);
+        b.append_u64(b"count", 7);
+
+        let mut out_a = [0u8; 48];
+        let mut out_b = [0u8; 48];
+        a.challenge_bytes(b"challenge", &mut out_a);
+        b.challenge_bytes(b"challenge", &mut out_b);
+
+        assert_eq!(out_a, out_b);
+    }
+
+    /// Changing either the message or the label boundary between two
+    /// absorbed fields must change the resulting challenge, guarding
+    /// against the length-prefixing above being silently skipped.
+    #[test]
+    fn duplex_distinguishes_field_boundaries() {
+        let mut distinct_split = DuplexHashChainTranscript::new(b"duplex test");
+        distinct_split.append_message(b"msg", b"hello");
+        distinct_split.append_message(b"msg", b", world");
+
+        // Without length prefixes, this single message followed by its label
+        // would absorb exactly the same bytes as the two appends above.
+        let joined_message: Vec<u8> = b"hello"
+            .iter()
+            .chain(pad_label(b"msg").iter())
+            .chain(b", world".iter())
+            .cloned()
+            .collect();
+        let mut joined = DuplexHashChainTranscript::new(b"duplex test");
+        joined.append_message(b"msg", &joined_message);
+
+        let mut out_split = [0u8; 32];
+        let mut out_joined = [0u8; 32];
+        distinct_split.challenge_bytes(b"challenge", &mut out_split);
+        joined.challenge_bytes(b"challenge", &mut out_joined);
+
+        assert_ne!(out_split, out_joined);
+    }
+
+    /// Squeezing exactly `RATE` (136) bytes lands `offset` back on the rate
+    /// boundary, so the next absorb/squeeze hits the squeeze-to-absorb
+    /// phase change with a not-yet-flushed full block still pending. This
+    /// pins the resulting challenge against an independently-computed
+    /// reference so a regression in that boundary handling is caught,
+    /// rather than just checking the two sides are internally consistent.
+    #[test]
+    fn duplex_handles_rate_boundary_phase_change() {
+        let mut transcript = DuplexHashChainTranscript::new(b"duplex boundary test");
+        transcript.append_message(b"msg", b"hello, world");
+
+        let mut first = [0u8; DuplexHashChainTranscript::RATE];
+        transcript.challenge_bytes(b"first", &mut first);
+
+        transcript.append_message(b"msg2", b"more input after the boundary");
+
+        let mut second = [0u8; 32];
+        transcript.challenge_bytes(b"second", &mut second);
+
+        assert_eq!(
+            second,
+            [
+                0xe5, 0x71, 0x81, 0xee, 0x9c, 0x5a, 0x0e, 0xc2, 0xf4, 0x32, 0x3d, 0x98, 0x7b,
+                0xb2, 0xb6, 0x03, 0x76, 0x76, 0x02, 0x0f, 0x54, 0x38, 0x14, 0xf0, 0x03, 0x60,
+                0xbb, 0xf0, 0xb6, 0x47, 0xc0, 0x96,
+            ]
+        );
+    }
 }
 
 pub struct HashChainTranscriptRngBuilder {
@@ -113,6 +718,23 @@ impl HashChainTranscriptRngBuilder {
         self
     }
 
+    /// Rekey the transcript using a witness field element, serializing it
+    /// to canonical bytes before absorbing.
+    ///
+    /// The `label` parameter is metadata about `witness`.
+    pub fn rekey_with_witness_scalar<F: PrimeField>(
+        mut self,
+        label: &'static [u8],
+        witness: &F,
+    ) -> HashChainTranscriptRngBuilder {
+        let mut bytes = Vec::new();
+        witness
+            .serialize_compressed(&mut bytes)
+            .expect("serialization to a `Vec` should not fail");
+        self.transcript.append_message(label, &bytes);
+        self
+    }
+
     /// Use the supplied external `rng` to rekey the transcript, so
     /// that the finalized [`TranscriptRng`] is a PRF bound to
     /// randomness from the external RNG, as well as all other
@@ -163,3 +785,28 @@ impl rand_core::RngCore for HashChainTranscriptRng {
 }
 
 impl rand_core::CryptoRng for HashChainTranscriptRng {}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    /// A transcript serialized then deserialized mid-stream should produce
+    /// identical subsequent challenges to the original, unserialized one.
+    #[test]
+    fn serde_round_trip_preserves_challenges() {
+        let mut original = HashChainTranscript::new(b"test transcript");
+        original.append_message(b"msg", b"hello, world");
+
+        let serialized = serde_json::to_vec(&original).unwrap();
+        let mut restored: HashChainTranscript = serde_json::from_slice(&serialized).unwrap();
+
+        assert_eq!(original.state(), restored.state());
+
+        let mut expected = [0u8; 32];
+        let mut actual = [0u8; 32];
+        original.challenge_bytes(b"challenge", &mut expected);
+        restored.challenge_bytes(b"challenge", &mut actual);
+
+        assert_eq!(expected, actual);
+    }
+}